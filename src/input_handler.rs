@@ -23,8 +23,9 @@ impl UserInput {
         }
     }
 
-    /// Read user input and determine its state.
-    pub fn process_input(&mut self) -> (Option<Vec<&str>>, InputState) {
+    /// Read user input, determine its state, and substitute any `$?` token with
+    /// `last_exit_code` before dispatch.
+    pub fn process_input(&mut self, last_exit_code: i32) -> (Option<Vec<String>>, InputState) {
         self.buffer.clear();
 
         let read_input = match io::stdin().read_line(&mut self.buffer) {
@@ -38,7 +39,16 @@ impl UserInput {
         }
 
         let trimmed_input = self.buffer.trim();
-        let parsed_input: Vec<&str> = trimmed_input.split_whitespace().collect();
+        let parsed_input: Vec<String> = trimmed_input
+            .split_whitespace()
+            .map(|token| {
+                if token == "$?" {
+                    last_exit_code.to_string()
+                } else {
+                    token.to_string()
+                }
+            })
+            .collect();
 
         let input_state = if parsed_input.is_empty() {
             InputState::Empty
@@ -4,17 +4,29 @@
 
 use crate::utils::{write_output, IoState, WriteOutputError};
 use signal_hook::{consts::SIGINT, iterator::Signals};
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::write;
-use std::process::{Command, Stdio};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader};
+use std::os::unix::process::{CommandExt, ExitStatusExt};
+use std::process::{Command, ExitStatus, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 
 /* TODO:
  * Fix error messages without codes, details
  * See other inline todos
  */
 
+/// How long to poll a killed child for exit before escalating to `SIGKILL`.
+const TERMINATE_TIMEOUT: Duration = Duration::from_secs(2);
+/// How long to wait on a child after escalating to `SIGKILL` before giving up.
+const FORCE_KILL_TIMEOUT: Duration = Duration::from_millis(200);
+/// Interval between `try_wait()` polls while waiting for a killed child to exit.
+const TERMINATE_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
 #[derive(PartialEq)]
 pub enum ShellState {
     Running,
@@ -26,6 +38,7 @@ pub enum ShellError {
     WriteError(WriteOutputError),
     LockError(String),
     SignalError(String),
+    ParseError(String),
 }
 
 impl fmt::Display for ShellError {
@@ -34,6 +47,7 @@ impl fmt::Display for ShellError {
             ShellError::WriteError(err) => write!(f, "Write error: {}", err),
             ShellError::LockError(err) => write!(f, "Lock error: {}", err),
             ShellError::SignalError(err) => write!(f, "Signal error: {}", err),
+            ShellError::ParseError(err) => write!(f, "Parse error: {}", err),
         }
     }
 }
@@ -44,8 +58,35 @@ impl From<WriteOutputError> for ShellError {
     }
 }
 
+/// Requested file redirections for a command's standard streams.
+///
+/// `stdout`/`stderr` pair the target path with whether it should be appended to (`>>`) or
+/// truncated (`>`).
+#[derive(Default)]
+struct Redirections<'a> {
+    stdin: Option<&'a str>,
+    stdout: Option<(&'a str, bool)>,
+    stderr: Option<(&'a str, bool)>,
+}
+
+/// The exit code of the most recently completed foreground command, expandable as `$?`.
+///
+/// Commands terminated by a signal on Unix are reported as `128 + signal`, following the
+/// common shell convention.
+#[derive(Clone, Copy, Debug, Default)]
+struct LastExitStatus {
+    code: i32,
+}
+
 pub struct ShellCore {
-    pub active_child_process: Arc<Mutex<Option<std::process::Child>>>,
+    /// The foreground command's children, in pipeline order (a single non-pipeline command is
+    /// the one-element case). Every entry here, not just the last, is subject to the `SIGINT`
+    /// handler and the bounded-timeout termination path.
+    pub active_child_processes: Arc<Mutex<Vec<std::process::Child>>>,
+    /// Background jobs launched with a trailing `&`, keyed by job ID.
+    background_jobs: Arc<Mutex<HashMap<usize, std::process::Child>>>,
+    next_job_id: Arc<Mutex<usize>>,
+    last_exit_status: Arc<Mutex<LastExitStatus>>,
 }
 
 /// Manage the execution of commands and handle signals for the shell.
@@ -54,12 +95,16 @@ impl ShellCore {
     /// Instantiate a new ShellCore object with no active child process.
     pub fn new() -> Self {
         let shell_core = ShellCore {
-            active_child_process: Arc::new(Mutex::new(None)),
+            active_child_processes: Arc::new(Mutex::new(Vec::new())),
+            background_jobs: Arc::new(Mutex::new(HashMap::new())),
+            next_job_id: Arc::new(Mutex::new(1)),
+            last_exit_status: Arc::new(Mutex::new(LastExitStatus::default())),
         };
 
         if let Err(_) = shell_core.setup_signal_handler() {
             let _ = write_output("Failed to setup signal handler");
         }
+        shell_core.spawn_job_reaper();
         shell_core
     }
 
@@ -68,7 +113,7 @@ impl ShellCore {
         loop {
             write_output("-> ");
 
-            let (parsed_input, input_state) = user_input.process_input();
+            let (parsed_input, input_state) = user_input.process_input(self.last_exit_code());
 
             match input_state {
                 crate::input_handler::InputState::Empty => continue,
@@ -80,6 +125,7 @@ impl ShellCore {
             }
 
             if let Some(parsed_input) = parsed_input {
+                let parsed_input: Vec<&str> = parsed_input.iter().map(String::as_str).collect();
                 if self.dispatch_command(parsed_input) == ShellState::Exiting {
                     break;
                 }
@@ -87,6 +133,39 @@ impl ShellCore {
         }
     }
 
+    /// The exit code of the most recently completed foreground command, as read by `$?`.
+    fn last_exit_code(&self) -> i32 {
+        match self.last_exit_status.lock() {
+            Ok(status) => status.code,
+            Err(_) => 0,
+        }
+    }
+
+    /// Record a foreground command's exit status for `$?`, printing nonzero exits distinctly.
+    fn record_exit_status(&self, status: ExitStatus) {
+        let (code, signal) = match status.code() {
+            Some(code) => (code, None),
+            None => (128 + status.signal().unwrap_or(0), status.signal()),
+        };
+
+        if let Ok(mut last_status) = self.last_exit_status.lock() {
+            *last_status = LastExitStatus { code };
+        }
+
+        if code != 0 {
+            match signal {
+                Some(signal) => write_output(
+                    format!(
+                        "Command terminated by signal {} (exit code {})\n",
+                        signal, code
+                    )
+                    .as_str(),
+                ),
+                None => write_output(format!("Command exited with code {}\n", code).as_str()),
+            };
+        }
+    }
+
     /// Dispatch the command based on the parsed input; execute either a built-in command or a system command.
     fn dispatch_command(&self, parsed_input: Vec<&str>) -> ShellState {
         /* TODO: should we really need to return shell state on dispatch?
@@ -101,7 +180,6 @@ impl ShellCore {
 
         // TODO: input_handler should take of this
         let command = parsed_input[0];
-        let arguments = &parsed_input[1..];
 
         match command {
             "help" => {
@@ -109,8 +187,41 @@ impl ShellCore {
                 ShellState::Running
             }
             "exit" => ShellState::Exiting,
+            "jobs" => {
+                self.list_jobs();
+                ShellState::Running
+            }
+            "fg" => {
+                if let Err(e) = self.bring_to_foreground(&parsed_input[1..]) {
+                    let _ =
+                        write_output(("Failed to bring job to foreground", e.to_string().as_str()));
+                }
+                ShellState::Running
+            }
+            "grep" => {
+                if let Err(e) = self.run_grep_builtin(&parsed_input[1..]) {
+                    let _ = write_output(("Failed to run grep", e.to_string().as_str()));
+                }
+                ShellState::Running
+            }
             _ => {
-                if let Err(e) = self.run_system_command(command, arguments) {
+                let background = parsed_input.last() == Some(&"&");
+                let tokens: &[&str] = if background {
+                    &parsed_input[..parsed_input.len() - 1]
+                } else {
+                    &parsed_input
+                };
+                let arguments = &tokens[1..];
+
+                let result = if background {
+                    self.run_background_command(command, arguments)
+                } else if tokens.contains(&"|") {
+                    self.run_pipeline(tokens)
+                } else {
+                    self.run_system_command(command, arguments)
+                };
+
+                if let Err(e) = result {
                     let _ = write_output(("Failed to execute command", e.to_string().as_str()));
                 }
                 ShellState::Running
@@ -119,52 +230,504 @@ impl ShellCore {
     }
 
     fn run_system_command(&self, command: &str, arguments: &[&str]) -> Result<(), ShellError> {
+        let (arguments, redirections) = Self::extract_redirections(arguments)?;
+        let (stdin, stdout, stderr) = Self::stdio_for_redirections(&redirections)?;
+
         let child_process = Command::new(command)
+            .args(&arguments)
+            .stdin(stdin)
+            .stdout(stdout)
+            .stderr(stderr)
+            .spawn()
+            .map_err(|e| ShellError::SignalError(e.to_string()))?;
+
+        self.set_active_child_processes(vec![child_process])?;
+        self.wait_for_foreground()?;
+
+        Ok(())
+    }
+
+    /// Split `>`, `>>`, `<` and `2>` redirection operators (and their filename operands) out of
+    /// an argument list, returning the remaining arguments alongside the requested redirections.
+    fn extract_redirections<'a>(
+        arguments: &[&'a str],
+    ) -> Result<(Vec<&'a str>, Redirections<'a>), ShellError> {
+        let mut remaining = Vec::new();
+        let mut redirections = Redirections::default();
+
+        let mut tokens = arguments.iter();
+        while let Some(&token) = tokens.next() {
+            match token {
+                ">" | ">>" | "<" | "2>" => {
+                    let filename = *tokens.next().ok_or_else(|| {
+                        ShellError::ParseError(format!("Missing filename after '{}'.", token))
+                    })?;
+                    match token {
+                        ">" => redirections.stdout = Some((filename, false)),
+                        ">>" => redirections.stdout = Some((filename, true)),
+                        "<" => redirections.stdin = Some(filename),
+                        "2>" => redirections.stderr = Some((filename, false)),
+                        _ => unreachable!(),
+                    }
+                }
+                _ => remaining.push(token),
+            }
+        }
+
+        Ok((remaining, redirections))
+    }
+
+    /// Build `Stdio` handles for a command's standard streams from parsed redirections, falling
+    /// back to inheriting the shell's own streams where no redirection was requested.
+    fn stdio_for_redirections(
+        redirections: &Redirections,
+    ) -> Result<(Stdio, Stdio, Stdio), ShellError> {
+        let stdin = match redirections.stdin {
+            Some(path) => Stdio::from(File::open(path).map_err(|e| {
+                ShellError::ParseError(format!(
+                    "Failed to open '{}' for input redirection: {}",
+                    path, e
+                ))
+            })?),
+            None => Stdio::inherit(),
+        };
+        let stdout = match redirections.stdout {
+            Some((path, append)) => Stdio::from(Self::open_redirect_file(path, append)?),
+            None => Stdio::inherit(),
+        };
+        let stderr = match redirections.stderr {
+            Some((path, append)) => Stdio::from(Self::open_redirect_file(path, append)?),
+            None => Stdio::inherit(),
+        };
+
+        Ok((stdin, stdout, stderr))
+    }
+
+    /// Open a file for output redirection, truncating unless `append` is set.
+    fn open_redirect_file(path: &str, append: bool) -> Result<File, ShellError> {
+        let file = if append {
+            OpenOptions::new().append(true).create(true).open(path)
+        } else {
+            File::create(path)
+        };
+
+        file.map_err(|e| {
+            ShellError::ParseError(format!(
+                "Failed to open '{}' for output redirection: {}",
+                path, e
+            ))
+        })
+    }
+
+    /// Run `command`, streaming its stdout line-by-line through `on_line` as it arrives, and
+    /// return every line read alongside the process's exit status.
+    ///
+    /// Each line is handed to `on_line` as soon as it is read rather than after the command
+    /// finishes. Every line is also kept for the returned `Vec`, so memory use grows with the
+    /// amount of output. Intended for built-ins that need to inspect a command's output (e.g. a
+    /// `grep`-style filter) without forcing it to inherit the terminal.
+    ///
+    /// The child is registered as the active child process for the duration of the read, same
+    /// as any other foreground command, so it gets killed by `SIGINT` or the bounded-timeout
+    /// path instead of being leaked as an orphan. It is not a background job and is not listed
+    /// by `jobs`.
+    pub(crate) fn run_capturing<F>(
+        &self,
+        command: &str,
+        arguments: &[&str],
+        mut on_line: F,
+    ) -> Result<(Vec<String>, ExitStatus), ShellError>
+    where
+        F: FnMut(&str),
+    {
+        let mut child = Command::new(command)
             .args(arguments)
             .stdin(Stdio::inherit())
-            .stdout(Stdio::inherit())
+            .stdout(Stdio::piped())
             .stderr(Stdio::inherit())
             .spawn()
             .map_err(|e| ShellError::SignalError(e.to_string()))?;
 
-        self.set_active_child_process(child_process)?;
+        let stdout = child.stdout.take().expect("stdout was piped");
+        self.set_active_child_processes(vec![child])?;
+
+        let mut lines = Vec::new();
+        for line in BufReader::new(stdout).lines() {
+            let line = line.map_err(|e| ShellError::SignalError(e.to_string()))?;
+            on_line(&line);
+            lines.push(line);
+        }
+
+        let status = self
+            .wait_for_foreground_status()?
+            .expect("run_capturing always registers exactly one active child process");
+
+        Ok((lines, status))
+    }
+
+    /// Built-in `grep <pattern> <command> [args...]`: run `command`, streaming its output
+    /// through `run_capturing` and printing only the lines that contain `pattern`.
+    fn run_grep_builtin(&self, arguments: &[&str]) -> Result<(), ShellError> {
+        let usage = || ShellError::ParseError("Usage: grep <pattern> <command> [args...]".into());
+
+        let (&pattern, remainder) = arguments.split_first().ok_or_else(usage)?;
+        let (&command, command_args) = remainder.split_first().ok_or_else(usage)?;
+
+        // Exit status is already recorded as $? by run_capturing; grep only needs the lines.
+        let (_, _status) = self.run_capturing(command, command_args, |line| {
+            if line.contains(pattern) {
+                write_output(format!("{}\n", line).as_str());
+            }
+        })?;
+
+        Ok(())
+    }
+
+    /// Spawn `command` in the background, registering it in the job table under a fresh job ID
+    /// and printing `[id] pid` as real shells do.
+    fn run_background_command(&self, command: &str, arguments: &[&str]) -> Result<(), ShellError> {
+        if arguments.contains(&"|") {
+            return Err(ShellError::ParseError(
+                "Pipelines are not supported for background (&) jobs.".into(),
+            ));
+        }
+
+        let (arguments, redirections) = Self::extract_redirections(arguments)?;
+        let (stdin, stdout, stderr) = Self::stdio_for_redirections(&redirections)?;
+
+        let mut cmd = Command::new(command);
+        cmd.args(&arguments).stdin(stdin).stdout(stdout).stderr(stderr);
+        Self::detach_process_group(&mut cmd);
+
+        let child = cmd
+            .spawn()
+            .map_err(|e| ShellError::SignalError(e.to_string()))?;
+
+        let pid = child.id();
+        let job_id = self.register_background_job(child)?;
+
+        write_output(format!("[{}] {}\n", job_id, pid).as_str());
+        Ok(())
+    }
+
+    /// Put a background child in its own process group so that a `SIGINT` delivered to the
+    /// shell's own foreground process group (e.g. Ctrl-C at the prompt) does not also reach it.
+    fn detach_process_group(cmd: &mut Command) {
+        unsafe {
+            cmd.pre_exec(|| {
+                if libc::setpgid(0, 0) != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                Ok(())
+            });
+        }
+    }
+
+    /// Assign the next job ID and register a background child in the job table.
+    fn register_background_job(&self, child: std::process::Child) -> Result<usize, ShellError> {
+        let mut next_id = self
+            .next_job_id
+            .lock()
+            .map_err(|_| ShellError::LockError("Failed to acquire lock for next job id.".into()))?;
+        let job_id = *next_id;
+        *next_id += 1;
+        drop(next_id);
+
+        let mut jobs = self.background_jobs.lock().map_err(|_| {
+            ShellError::LockError("Failed to acquire lock for job table.".into())
+        })?;
+        jobs.insert(job_id, child);
+
+        Ok(job_id)
+    }
+
+    /// List background jobs as `[id] pid`.
+    fn list_jobs(&self) {
+        let jobs = match self.background_jobs.lock() {
+            Ok(jobs) => jobs,
+            Err(_) => {
+                let _ = write_output("Failed to acquire lock for job table.");
+                return;
+            }
+        };
+
+        if jobs.is_empty() {
+            write_output("No background jobs.\n");
+            return;
+        }
+
+        let mut ids: Vec<&usize> = jobs.keys().collect();
+        ids.sort();
+        for id in ids {
+            write_output(format!("[{}] {}\n", id, jobs[id].id()).as_str());
+        }
+    }
+
+    /// Bring a background job to the foreground and wait for it to finish.
+    ///
+    /// The job is installed as the active child process so it shares the normal foreground
+    /// command's interrupt (`SIGINT`) and bounded-timeout termination path.
+    fn bring_to_foreground(&self, arguments: &[&str]) -> Result<(), ShellError> {
+        let job_id: usize = arguments
+            .first()
+            .and_then(|arg| arg.parse().ok())
+            .ok_or_else(|| ShellError::ParseError("Usage: fg <id>".into()))?;
+
+        let child = {
+            let mut jobs = self.background_jobs.lock().map_err(|_| {
+                ShellError::LockError("Failed to acquire lock for job table.".into())
+            })?;
+            jobs.remove(&job_id)
+                .ok_or_else(|| ShellError::ParseError(format!("No such job [{}].", job_id)))?
+        };
+
+        self.set_active_child_processes(vec![child])?;
+        self.wait_for_foreground()?;
+        Ok(())
+    }
+
+    /// Spawn a background thread that periodically reaps finished background jobs.
+    ///
+    /// Modeled on an orphan-queue design: the thread polls each background job with
+    /// `try_wait()` so exited children are removed and reported as soon as they finish,
+    /// without the shell loop ever blocking on them.
+    fn spawn_job_reaper(&self) {
+        let jobs_clone = Arc::clone(&self.background_jobs);
+
+        thread::spawn(move || loop {
+            thread::sleep(Duration::from_millis(500));
+
+            let mut jobs = match jobs_clone.lock() {
+                Ok(jobs) => jobs,
+                Err(_) => continue,
+            };
+
+            let mut finished = Vec::new();
+            for (&id, child) in jobs.iter_mut() {
+                if let Ok(Some(status)) = child.try_wait() {
+                    finished.push((id, status));
+                }
+            }
+
+            for (id, status) in finished {
+                jobs.remove(&id);
+                write_output(format!("[{}]+ Done ({})\n", id, status).as_str());
+            }
+        });
+    }
+
+    /// Run a pipeline of commands separated by `|`, wiring each segment's stdout into the next
+    /// segment's stdin via `Stdio::piped()`.
+    ///
+    /// Every segment, not just the last, is registered as an active child process, so each one
+    /// shares the same `SIGINT`/bounded-timeout handling as a plain foreground command instead of
+    /// being reaped with a plain blocking `wait()` that the shell can't interrupt.
+    ///
+    /// Redirection operators (`>`, `>>`, `<`, `2>`) are not recognized on pipeline segments; a
+    /// segment containing one passes it through to the command as a literal argument.
+    fn run_pipeline(&self, parsed_input: &[&str]) -> Result<(), ShellError> {
+        let segments: Vec<&[&str]> = parsed_input.split(|token: &&str| *token == "|").collect();
+
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(ShellError::ParseError(
+                "Empty command segment in pipeline.".into(),
+            ));
+        }
+
+        let last_index = segments.len() - 1;
+        let mut previous_stdout: Option<std::process::ChildStdout> = None;
+        let mut spawned_children: Vec<std::process::Child> = Vec::new();
+
+        for (index, segment) in segments.iter().enumerate() {
+            let command = segment[0];
+            let arguments = &segment[1..];
+
+            let stdin = match previous_stdout.take() {
+                Some(stdout) => Stdio::from(stdout),
+                None => Stdio::inherit(),
+            };
+            let stdout = if index == last_index {
+                Stdio::inherit()
+            } else {
+                Stdio::piped()
+            };
+
+            let mut child = match Command::new(command)
+                .args(arguments)
+                .stdin(stdin)
+                .stdout(stdout)
+                .stderr(Stdio::inherit())
+                .spawn()
+            {
+                Ok(child) => child,
+                Err(e) => {
+                    for mut spawned in spawned_children {
+                        let _ = Self::kill_and_reap(&mut spawned);
+                    }
+                    return Err(ShellError::SignalError(e.to_string()));
+                }
+            };
+
+            previous_stdout = child.stdout.take();
+            spawned_children.push(child);
+        }
+
+        self.set_active_child_processes(spawned_children)?;
+        self.wait_for_foreground()?;
 
         Ok(())
     }
 
-    /// Set the active child process, replacing and cleaning up any existing process.
-    fn set_active_child_process(&self, child_proc: std::process::Child) -> Result<(), ShellError> {
+    /// Poll every active child process until all have exited, recording the exit status of the
+    /// last one (the whole pipeline's result, or the only child for a non-pipeline command) for
+    /// `$?`.
+    ///
+    /// Polling with `try_wait()` rather than a blocking `wait()` keeps the mutex free between
+    /// checks, so the `SIGINT` handler can still reach in and kill any of them, including
+    /// earlier pipeline segments.
+    fn wait_for_foreground(&self) -> Result<(), ShellError> {
+        self.wait_for_foreground_status().map(|_| ())
+    }
+
+    /// Like `wait_for_foreground`, but also returns the last process's `ExitStatus` (`None` if
+    /// there was nothing active to wait for), for callers that need more than the `i32` code
+    /// recorded for `$?`.
+    fn wait_for_foreground_status(&self) -> Result<Option<ExitStatus>, ShellError> {
+        let last_pid = {
+            let children = self.active_child_processes.lock().map_err(|_| {
+                ShellError::LockError("Failed to acquire lock for child process.".into())
+            })?;
+            match children.last() {
+                Some(child) => child.id(),
+                None => return Ok(None),
+            }
+        };
+
+        let mut last_status = None;
+        loop {
+            let mut children = self.active_child_processes.lock().map_err(|_| {
+                ShellError::LockError("Failed to acquire lock for child process.".into())
+            })?;
+
+            let mut index = 0;
+            while index < children.len() {
+                match children[index].try_wait() {
+                    Ok(Some(status)) => {
+                        let mut finished = children.remove(index);
+                        let _ = finished.wait();
+                        if finished.id() == last_pid {
+                            last_status = Some(status);
+                        }
+                    }
+                    Ok(None) => index += 1,
+                    Err(e) => return Err(ShellError::SignalError(e.to_string())),
+                }
+            }
+
+            if children.is_empty() {
+                drop(children);
+                if let Some(status) = last_status {
+                    self.record_exit_status(status);
+                }
+                return Ok(last_status);
+            }
+
+            drop(children);
+            thread::sleep(TERMINATE_POLL_INTERVAL);
+        }
+    }
+
+    /// Set the active child processes, replacing and cleaning up any existing ones.
+    fn set_active_child_processes(
+        &self,
+        children: Vec<std::process::Child>,
+    ) -> Result<(), ShellError> {
         // Clean-up any artifacts (not possible to spawn concurrent user-commands from the same shell instance)
-        self.terminate_child_process()?;
+        self.terminate_child_processes()?;
 
         // update handle
-        let mut handle_child_process = self.active_child_process.lock().map_err(|_| {
+        let mut handle_child_processes = self.active_child_processes.lock().map_err(|_| {
             ShellError::LockError("Failed to acquire lock for child process.".into())
         })?;
-        *handle_child_process = Some(child_proc);
+        *handle_child_processes = children;
         Ok(())
     }
 
-    /// Terminate active child process: wait for it to finish and clear.
-    fn terminate_child_process(&self) -> Result<(), ShellError> {
-        let mut handle_child_process = self.active_child_process.lock().map_err(|_| {
+    /// Terminate every active child process: kill each, wait for it to exit within a bounded
+    /// timeout, and clear the handles. Records the last process's exit status for `$?`.
+    fn terminate_child_processes(&self) -> Result<(), ShellError> {
+        let mut children = self.active_child_processes.lock().map_err(|_| {
             ShellError::LockError("Failed to acquire lock for child process.".into())
         })?;
 
-        if let Some(ref mut child) = *handle_child_process {
-            if let Err(e) = child.kill() {
-                let _ = write_output(("Failed to kill child process", e.to_string().as_str()));
+        let last_index = children.len().checked_sub(1);
+        for (index, child) in children.iter_mut().enumerate() {
+            let status = Self::kill_and_reap(child)?;
+
+            if Some(index) == last_index {
+                self.record_exit_status(status);
             }
-            // TODO: what if it hangs? need a timeout; wait-timeout.rs could be used
-            child
-                .wait()
-                .map_err(|e| ShellError::SignalError(e.to_string()))?;
         }
 
-        *handle_child_process = None;
+        children.clear();
         Ok(())
     }
 
+    /// Kill `child` and wait for it to exit within a bounded timeout, escalating to a raw
+    /// `SIGKILL` via `force_kill` if it doesn't respond in time.
+    ///
+    /// Shared by `terminate_child_processes` and `run_pipeline`'s spawn-failure cleanup, so an
+    /// already-spawned segment can never outlive the shell as a zombie just because a later
+    /// segment in the same pipeline failed to spawn.
+    fn kill_and_reap(child: &mut std::process::Child) -> Result<ExitStatus, ShellError> {
+        Self::request_termination(child);
+
+        let deadline = Instant::now() + TERMINATE_TIMEOUT;
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) => return Ok(status),
+                Ok(None) if Instant::now() < deadline => thread::sleep(TERMINATE_POLL_INTERVAL),
+                Ok(None) => return Self::force_kill(child),
+                Err(e) => return Err(ShellError::SignalError(e.to_string())),
+            }
+        }
+    }
+
+    /// Ask a child to exit via `SIGTERM`, giving it a chance to clean up.
+    ///
+    /// `std::process::Child::kill()` always sends `SIGKILL` on Unix, which leaves nothing
+    /// milder to escalate to afterwards, so this sends the raw signal directly via `libc`
+    /// instead.
+    fn request_termination(child: &std::process::Child) {
+        unsafe {
+            libc::kill(child.id() as i32, libc::SIGTERM);
+        }
+    }
+
+    /// Escalate to a raw `SIGKILL` for a child that ignored `SIGTERM`, then wait once more with
+    /// a short bound so the shell loop can never hang on an unresponsive process.
+    fn force_kill(child: &mut std::process::Child) -> Result<ExitStatus, ShellError> {
+        let pid = child.id() as i32;
+        unsafe {
+            libc::kill(pid, libc::SIGKILL);
+        }
+
+        let deadline = Instant::now() + FORCE_KILL_TIMEOUT;
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) => return Ok(status),
+                Ok(None) if Instant::now() < deadline => thread::sleep(TERMINATE_POLL_INTERVAL),
+                Ok(None) => {
+                    return Err(ShellError::SignalError(format!(
+                        "Process {} did not respond to SIGKILL within the termination deadline.",
+                        pid
+                    )))
+                }
+                Err(e) => return Err(ShellError::SignalError(e.to_string())),
+            }
+        }
+    }
+
     /// Sets up a signal handler for `SIGINT` (CTRL-C).
     ///
     /// Spawns a new thread to listen for `SIGINT` signals. Upon detection,
@@ -173,25 +736,29 @@ impl ShellCore {
         let mut signals =
             Signals::new(&[SIGINT]).map_err(|e| ShellError::SignalError(e.to_string()))?;
 
-        let child_clone = Arc::clone(&self.active_child_process);
+        let children_clone = Arc::clone(&self.active_child_processes);
 
         thread::spawn(move || {
             for _ in signals.forever() {
-                let mut handle_child_proc = match child_clone.lock() {
+                let mut children = match children_clone.lock() {
                     Ok(handle) => handle,
                     Err(_) => {
                         let _ = write_output("Failed to acquire lock for signal handler.");
                         continue;
                     }
                 };
-                if let Some(ref mut child) = *handle_child_proc {
+                if children.is_empty() {
+                    write_output("\n-> ");
+                } else {
                     let _ = write_output("CTRL-C detected. Terminating active task.\n");
-                    if let Err(e) = child.kill() {
-                        let _ =
-                            write_output(("Failed to kill child process", e.to_string().as_str()));
+                    for child in children.iter_mut() {
+                        if let Err(e) = child.kill() {
+                            let _ = write_output((
+                                "Failed to kill child process",
+                                e.to_string().as_str(),
+                            ));
+                        }
                     }
-                } else {
-                    write_output("\n-> ");
                 }
             }
         });
@@ -205,6 +772,9 @@ impl ShellCore {
                             Available commands:
                             help - Show this help message
                             exit - Exit the shell
+                            jobs - List background jobs
+                            fg <id> - Bring a background job to the foreground
+                            grep <pattern> <command> [args...] - Run a command and print matching lines
                         "#;
 
         write_output(help_msg);